@@ -0,0 +1,555 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+#[path = "state.rs"]
+mod state;
+
+use fungible::Account;
+use gen_nft::{
+    Collection, GenNftAbi, GenerationRecipe, Message, Metadata, MintPolicy, Nft, Operation,
+    Provenance, Royalty,
+};
+use linera_sdk::{
+    base::{AccountOwner, Amount, WithContractAbi},
+    Contract, ContractRuntime,
+};
+
+use self::state::GenNftState;
+
+pub struct GenNftContract {
+    state: GenNftState,
+    runtime: ContractRuntime<Self>,
+}
+
+linera_sdk::contract!(GenNftContract);
+
+impl WithContractAbi for GenNftContract {
+    type Abi = GenNftAbi;
+}
+
+impl Contract for GenNftContract {
+    type Message = Message;
+    type Parameters = ();
+    type InstantiationArgument = ();
+    type EventValue = ();
+
+    async fn load(runtime: ContractRuntime<Self>) -> Self {
+        let state = GenNftState::load(runtime.root_view_storage_context())
+            .await
+            .expect("failed to load state");
+        GenNftContract { state, runtime }
+    }
+
+    async fn instantiate(&mut self, _argument: ()) {
+        self.runtime.application_parameters();
+    }
+
+    async fn execute_operation(&mut self, operation: Operation) {
+        match operation {
+            Operation::CreateCollection {
+                name,
+                symbol,
+                max_supply,
+                mint_policy,
+            } => self
+                .execute_create_collection(name, symbol, max_supply, mint_policy)
+                .await,
+            Operation::Mint {
+                minter,
+                collection_id,
+                recipe,
+                metadata,
+                royalty,
+            } => {
+                self.execute_mint(minter, collection_id, recipe, metadata, royalty)
+                    .await
+            }
+            Operation::Transfer {
+                source_owner,
+                token_id,
+                target_account,
+            } => {
+                self.execute_transfer(source_owner, token_id, target_account)
+                    .await
+            }
+            Operation::TransferWithPayment {
+                source_owner,
+                token_id,
+                target_account,
+                payment_application_id,
+                payment_amount,
+            } => {
+                self.execute_transfer_with_payment(
+                    source_owner,
+                    token_id,
+                    target_account,
+                    payment_application_id,
+                    payment_amount,
+                )
+                .await
+            }
+            Operation::Claim {
+                source_account,
+                token_id,
+                target_account,
+            } => {
+                if source_account.chain_id == self.runtime.chain_id() {
+                    // Fail fast when the source account is local instead of waiting for the
+                    // round trip through `Message::Claim` to reject it.
+                    let signer = self
+                        .runtime
+                        .authenticated_signer()
+                        .expect("claim must be authenticated");
+                    let nft = self
+                        .state
+                        .nfts
+                        .get(&token_id)
+                        .await
+                        .expect("view access should not fail")
+                        .expect("claimed token should exist");
+                    assert_eq!(nft.owner, source_account.owner, "claim source mismatch");
+                    self.assert_authorized_spender(&nft, &signer).await;
+                }
+                self.runtime
+                    .prepare_message(Message::Claim {
+                        source_account,
+                        token_id,
+                        target_account,
+                    })
+                    .with_authentication()
+                    .send_to(source_account.chain_id);
+            }
+            Operation::Burn {
+                source_account,
+                token_id,
+            } => self.execute_burn(source_account, token_id).await,
+            Operation::Approve { token_id, approved } => {
+                self.execute_approve(token_id, approved).await
+            }
+            Operation::SetApprovalForAll { operator, approved } => {
+                self.execute_set_approval_for_all(operator, approved).await
+            }
+        }
+    }
+
+    async fn execute_message(&mut self, message: Message) {
+        match message {
+            Message::Transfer { nft, target_account } => {
+                self.on_receive_transfer(nft, target_account).await
+            }
+            Message::Claim {
+                source_account,
+                token_id,
+                target_account,
+            } => {
+                let signer = self
+                    .runtime
+                    .authenticated_signer()
+                    .expect("claim message must be authenticated");
+                let nft = self
+                    .state
+                    .nfts
+                    .get(&token_id)
+                    .await
+                    .expect("view access should not fail")
+                    .expect("claimed token should exist");
+                assert_eq!(nft.owner, source_account.owner, "claim source mismatch");
+                self.assert_authorized_spender(&nft, &signer).await;
+                self.remove_nft(&source_account.owner, &token_id).await;
+                self.runtime
+                    .prepare_message(Message::Transfer { nft, target_account })
+                    .with_authentication()
+                    .send_to(target_account.chain_id);
+            }
+            Message::Burn {
+                source_account,
+                token_id,
+            } => {
+                let signer = self
+                    .runtime
+                    .authenticated_signer()
+                    .expect("burn message must be authenticated");
+                let nft = self
+                    .state
+                    .nfts
+                    .get(&token_id)
+                    .await
+                    .expect("view access should not fail")
+                    .expect("burned token should exist");
+                assert_eq!(nft.owner, source_account.owner, "burn source mismatch");
+                self.assert_authorized_spender(&nft, &signer).await;
+                self.remove_nft(&source_account.owner, &token_id).await;
+            }
+        }
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("failed to save state");
+    }
+}
+
+impl GenNftContract {
+    async fn execute_create_collection(
+        &mut self,
+        name: String,
+        symbol: String,
+        max_supply: Option<u64>,
+        mint_policy: MintPolicy,
+    ) {
+        let creator = self
+            .runtime
+            .authenticated_signer()
+            .expect("collection creation must be authenticated");
+        let num_created_collections = *self.state.num_created_collections.get();
+        let collection_id = Collection::create_collection_id(
+            &self.runtime.chain_id(),
+            &self.runtime.application_id().forget_abi(),
+            &name,
+            &symbol,
+            &creator,
+            num_created_collections,
+        )
+        .expect("failed to derive collection id");
+
+        self.state
+            .collections
+            .insert(
+                &collection_id,
+                Collection {
+                    collection_id,
+                    name,
+                    symbol,
+                    creator,
+                    max_supply,
+                    mint_policy,
+                    minted_count: 0,
+                },
+            )
+            .expect("failed to insert collection");
+        self.state
+            .num_created_collections
+            .set(num_created_collections + 1);
+    }
+
+    async fn execute_mint(
+        &mut self,
+        minter: AccountOwner,
+        collection_id: gen_nft::CollectionId,
+        recipe: GenerationRecipe,
+        metadata: Option<Metadata>,
+        royalty: Option<Royalty>,
+    ) {
+        let signer = self
+            .runtime
+            .authenticated_signer()
+            .expect("mint must be authenticated");
+        assert_eq!(signer, minter, "mint must be signed by minter");
+
+        if let Some(royalty) = &royalty {
+            assert!(royalty.is_valid(), "royalty basis_points must be <= 10_000");
+        }
+
+        let mut collection = self
+            .state
+            .collections
+            .get(&collection_id)
+            .await
+            .expect("view access should not fail")
+            .expect("minting into an unknown collection");
+        assert!(
+            collection.can_mint(&minter),
+            "minter is not authorized to mint into this collection"
+        );
+        assert!(
+            collection.has_remaining_supply(),
+            "collection has reached its max_supply"
+        );
+
+        let mint_sequence = *self.state.num_minted_nfts.get();
+        let token_id = Nft::create_token_id(
+            &self.runtime.chain_id(),
+            &self.runtime.application_id().forget_abi(),
+            &collection_id,
+            &recipe,
+            &minter,
+            mint_sequence,
+        )
+        .expect("failed to derive token id");
+
+        let metadata =
+            metadata.unwrap_or_else(|| Metadata::synthesized(&recipe.prompt, &recipe.prompt));
+
+        let nft = Nft {
+            token_id: token_id.clone(),
+            owner: minter,
+            minter,
+            collection_id,
+            mint_sequence,
+            recipe,
+            metadata,
+            approved: None,
+            royalty,
+            origin: None,
+        };
+
+        self.state
+            .nfts
+            .insert(&token_id, nft)
+            .expect("failed to insert nft");
+        self.add_owned_token(&minter, &token_id).await;
+        collection.minted_count += 1;
+        self.state
+            .collections
+            .insert(&collection_id, collection)
+            .expect("failed to update collection");
+        self.state.num_minted_nfts.set(mint_sequence + 1);
+    }
+
+    async fn execute_transfer(
+        &mut self,
+        source_owner: AccountOwner,
+        token_id: gen_nft::TokenId,
+        target_account: Account,
+    ) {
+        let signer = self
+            .runtime
+            .authenticated_signer()
+            .expect("transfer must be authenticated");
+        let mut nft = self
+            .state
+            .nfts
+            .get(&token_id)
+            .await
+            .expect("view access should not fail")
+            .expect("transferred token should exist");
+        assert_eq!(nft.owner, source_owner, "transfer source mismatch");
+        self.assert_authorized_spender(&nft, &signer).await;
+
+        if target_account.chain_id == self.runtime.chain_id() {
+            self.remove_owned_token(&nft.owner, &token_id).await;
+            nft.owner = target_account.owner;
+            nft.approved = None;
+            self.add_owned_token(&target_account.owner, &token_id).await;
+            self.state
+                .nfts
+                .insert(&token_id, nft)
+                .expect("failed to update nft");
+        } else {
+            self.remove_nft(&source_owner, &token_id).await;
+            self.runtime
+                .prepare_message(Message::Transfer { nft, target_account })
+                .with_authentication()
+                .send_to(target_account.chain_id);
+        }
+    }
+
+    async fn execute_transfer_with_payment(
+        &mut self,
+        source_owner: AccountOwner,
+        token_id: gen_nft::TokenId,
+        target_account: Account,
+        payment_application_id: linera_sdk::base::ApplicationId,
+        payment_amount: Amount,
+    ) {
+        let nft = self
+            .state
+            .nfts
+            .get(&token_id)
+            .await
+            .expect("view access should not fail")
+            .expect("transferred token should exist");
+        let buyer = target_account.owner;
+        let payment_application_id = payment_application_id.with_abi::<fungible::FungibleTokenAbi>();
+
+        let payout = nft.royalty.as_ref().map_or(Amount::ZERO, |royalty| {
+            let payout = royalty.payout(payment_amount);
+            self.runtime.call_application(
+                true,
+                payment_application_id,
+                &fungible::Operation::Transfer {
+                    owner: buyer,
+                    amount: payout,
+                    target_account: Account {
+                        chain_id: self.runtime.chain_id(),
+                        owner: royalty.recipient,
+                    },
+                },
+            );
+            payout
+        });
+
+        let sale_proceeds = Amount::from(u128::from(payment_amount) - u128::from(payout));
+        self.runtime.call_application(
+            true,
+            payment_application_id,
+            &fungible::Operation::Transfer {
+                owner: buyer,
+                amount: sale_proceeds,
+                target_account: Account {
+                    chain_id: self.runtime.chain_id(),
+                    owner: source_owner,
+                },
+            },
+        );
+
+        self.execute_transfer(source_owner, token_id, target_account)
+            .await;
+    }
+
+    async fn execute_burn(&mut self, source_account: Account, token_id: gen_nft::TokenId) {
+        if source_account.chain_id == self.runtime.chain_id() {
+            let signer = self
+                .runtime
+                .authenticated_signer()
+                .expect("burn must be authenticated");
+            let nft = self
+                .state
+                .nfts
+                .get(&token_id)
+                .await
+                .expect("view access should not fail")
+                .expect("burned token should exist");
+            assert_eq!(nft.owner, source_account.owner, "burn source mismatch");
+            self.assert_authorized_spender(&nft, &signer).await;
+            self.remove_nft(&source_account.owner, &token_id).await;
+        } else {
+            self.runtime
+                .prepare_message(Message::Burn {
+                    source_account,
+                    token_id,
+                })
+                .with_authentication()
+                .send_to(source_account.chain_id);
+        }
+    }
+
+    async fn execute_approve(&mut self, token_id: gen_nft::TokenId, approved: Option<AccountOwner>) {
+        let signer = self
+            .runtime
+            .authenticated_signer()
+            .expect("approve must be authenticated");
+        let mut nft = self
+            .state
+            .nfts
+            .get(&token_id)
+            .await
+            .expect("view access should not fail")
+            .expect("approved token should exist");
+        assert_eq!(nft.owner, signer, "only the owner may approve a spender");
+        nft.approved = approved;
+        self.state
+            .nfts
+            .insert(&token_id, nft)
+            .expect("failed to update nft");
+    }
+
+    async fn execute_set_approval_for_all(&mut self, operator: AccountOwner, approved: bool) {
+        let signer = self
+            .runtime
+            .authenticated_signer()
+            .expect("setApprovalForAll must be authenticated");
+        let mut operators = self
+            .state
+            .operators
+            .get(&signer)
+            .await
+            .expect("view access should not fail")
+            .unwrap_or_default();
+        if approved {
+            operators.insert(operator);
+        } else {
+            operators.remove(&operator);
+        }
+        self.state
+            .operators
+            .insert(&signer, operators)
+            .expect("failed to update operators");
+    }
+
+    async fn on_receive_transfer(&mut self, mut nft: Nft, target_account: Account) {
+        // The first hop away from the mint chain establishes provenance; later hops keep the
+        // original trail so that landing back on the origin chain recovers the original id.
+        let origin = nft.origin.clone().unwrap_or_else(|| Provenance {
+            origin_chain: self
+                .runtime
+                .message_id()
+                .expect("transfer should be delivered as a message")
+                .chain_id,
+            origin_app: self.runtime.application_id().forget_abi(),
+            original_token_id: nft.token_id.clone(),
+        });
+        let token_id = Nft::create_wrapped_token_id(
+            &self.runtime.chain_id(),
+            &self.runtime.application_id().forget_abi(),
+            &origin,
+        )
+        .expect("failed to derive wrapped token id");
+
+        nft.token_id = token_id.clone();
+        nft.origin = Some(origin);
+        nft.owner = target_account.owner;
+        nft.approved = None;
+        self.state
+            .nfts
+            .insert(&token_id, nft)
+            .expect("failed to insert nft");
+        self.add_owned_token(&target_account.owner, &token_id).await;
+    }
+
+    /// Asserts that `signer` may transfer, claim or burn `nft`: the owner, its approved
+    /// spender, or an operator the owner has approved via `SetApprovalForAll`.
+    async fn assert_authorized_spender(&self, nft: &Nft, signer: &AccountOwner) {
+        let is_operator = self
+            .state
+            .operators
+            .get(&nft.owner)
+            .await
+            .expect("view access should not fail")
+            .map_or(false, |operators| operators.contains(signer));
+        assert!(
+            nft.is_authorized_spender(signer, is_operator),
+            "signer is not the owner, an approved spender, or an approved operator of this token"
+        );
+    }
+
+    async fn remove_nft(&mut self, owner: &AccountOwner, token_id: &gen_nft::TokenId) {
+        self.state
+            .nfts
+            .remove(token_id)
+            .expect("failed to remove nft");
+        self.remove_owned_token(owner, token_id).await;
+    }
+
+    async fn add_owned_token(&mut self, owner: &AccountOwner, token_id: &gen_nft::TokenId) {
+        let mut owned = self
+            .state
+            .owned_token_ids
+            .get(owner)
+            .await
+            .expect("view access should not fail")
+            .unwrap_or_default();
+        owned.insert(token_id.clone());
+        self.state
+            .owned_token_ids
+            .insert(owner, owned)
+            .expect("failed to update owned token ids");
+    }
+
+    async fn remove_owned_token(&mut self, owner: &AccountOwner, token_id: &gen_nft::TokenId) {
+        if let Some(mut owned) = self
+            .state
+            .owned_token_ids
+            .get(owner)
+            .await
+            .expect("view access should not fail")
+        {
+            owned.remove(token_id);
+            self.state
+                .owned_token_ids
+                .insert(owner, owned)
+                .expect("failed to update owned token ids");
+        }
+    }
+}
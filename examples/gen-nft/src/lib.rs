@@ -91,17 +91,53 @@ linera service --port $PORT &
 Type each of these in the GraphiQL interface and substitute the env variables with their actual values that we've defined above.
 
 - Navigate to the URL you get by running `echo "http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID"`.
-- To mint an NFT, run the query:
+- To create a collection to mint into, run the query:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    mutation {
+        createCollection(
+            name: "Demo Collection",
+            symbol: "DEMO",
+            maxSupply: 100,
+            mintPolicy: "open"
+        )
+    }
+```
+
+Set the `COLLECTION_ID` variable to the `collectionId` of the collection returned by the
+`collections` query below.
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    query {
+        collections
+    }
+```
+
+- To mint an NFT into that collection, run the query:
 
 ```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
     mutation {
         mint(
             minter: "User:$OWNER_1",
-            prompt: "Hello!"
+            collectionId: "$COLLECTION_ID",
+            recipe: {
+                modelId: "gen-nft-demo",
+                prompt: "Hello!",
+                seed: 37,
+                temperatureMilli: 700,
+                maxTokens: 256
+            },
+            metadata: null,
+            royalty: {
+                recipient: "User:$OWNER_1",
+                basisPoints: 500
+            }
         )
     }
 ```
 
+Passing `metadata: null` makes the contract fill in both `name` and `description` with the recipe's `prompt`; pass a `MetadataInput` to set them (and any `attributes`) explicitly. The `royalty` is optional; when set, `TransferWithPayment` routes `basisPoints / 10000` of the sale price to `recipient`.
+
 - To check that it's assigned to the owner, run the query:
 
 ```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
@@ -124,8 +160,16 @@ TOKEN_ID=$(echo "$QUERY_RESULT" | jq -r '.ownedNfts[].tokenId')
         nft(tokenId: "$TOKEN_ID") {
             tokenId,
             owner,
-            prompt,
             minter,
+            collectionId,
+            recipe { modelId, prompt, seed, temperatureMilli, maxTokens },
+            metadata {
+                name,
+                description,
+                attributes { traitType, value }
+            },
+            royalty { recipient, basisPoints },
+            origin { originChain, originApp, originalTokenId }
         }
     }
 ```
@@ -138,6 +182,19 @@ TOKEN_ID=$(echo "$QUERY_RESULT" | jq -r '.ownedNfts[].tokenId')
     }
 ```
 
+- If the NFT was claimed or transferred in from another chain, to see where it originated,
+  run the query:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    query {
+        provenance(tokenId: "$TOKEN_ID") {
+            originChain,
+            originApp,
+            originalTokenId
+        }
+    }
+```
+
 - To transfer the NFT to user `$OWNER_2`, still on chain `$CHAIN_1`, run the query:
 
 ```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
@@ -153,6 +210,81 @@ TOKEN_ID=$(echo "$QUERY_RESULT" | jq -r '.ownedNfts[].tokenId')
     }
 ```
 
+- To instead sell the NFT for a payment in a fungible token application, paying out any
+  `royalty` before the transfer completes, run the query:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    mutation {
+        transferWithPayment(
+            sourceOwner: "User:$OWNER_1",
+            tokenId: "$TOKEN_ID",
+            targetAccount: {
+                chainId: "$CHAIN_1",
+                owner: "User:$OWNER_2"
+            },
+            paymentApplicationId: "$FUNGIBLE_APP_ID",
+            paymentAmount: "10."
+        )
+    }
+```
+
+- To enumerate the collection without fetching `nfts` in full, run the queries:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    query {
+        totalSupply
+        nftByIndex(index: 0)
+        tokenOfOwnerByIndex(owner: "User:$OWNER_1", index: 0)
+    }
+```
+
+- To look up a collection or list everything minted into it, run the queries:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    query {
+        collection(id: "$COLLECTION_ID")
+        nftsInCollection(id: "$COLLECTION_ID")
+    }
+```
+
+- To burn the NFT, removing it from the collection for good, run the query:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    mutation {
+        burn(
+            sourceAccount: {
+                chainId: "$CHAIN_1",
+                owner: "User:$OWNER_1"
+            },
+            tokenId: "$TOKEN_ID"
+        )
+    }
+```
+
+- To let `$OWNER_2` transfer or claim this one token on `$OWNER_1`'s behalf, or an
+  escrow/marketplace application act as an operator over all of `$OWNER_1`'s tokens, run:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    mutation {
+        approve(tokenId: "$TOKEN_ID", approved: "User:$OWNER_2")
+    }
+```
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    mutation {
+        setApprovalForAll(operator: "User:$OWNER_2", approved: true)
+    }
+```
+
+- To check who, if anyone, is approved, run the queries:
+
+```gql,uri=http://localhost:8080/chains/$CHAIN_1/applications/$APP_ID
+    query {
+        getApproved(tokenId: "$TOKEN_ID")
+        isApprovedForAll(owner: "User:$OWNER_1", operator: "User:$OWNER_2")
+    }
+```
+
 ### Using Web Frontend
 
 Installing and starting the web server:
@@ -178,7 +310,7 @@ use std::fmt::{Display, Formatter};
 use async_graphql::{InputObject, Request, Response, SimpleObject};
 use fungible::Account;
 use linera_sdk::{
-    base::{AccountOwner, ApplicationId, ChainId, ContractAbi, ServiceAbi},
+    base::{AccountOwner, Amount, ApplicationId, ChainId, ContractAbi, ServiceAbi},
     graphql::GraphQLMutationRoot,
     ToBcsBytes,
 };
@@ -192,6 +324,14 @@ pub struct TokenId {
     pub id: Vec<u8>,
 }
 
+#[derive(
+    Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Ord, PartialOrd, SimpleObject, InputObject,
+)]
+#[graphql(input_name = "CollectionIdInput")]
+pub struct CollectionId {
+    pub id: Vec<u8>,
+}
+
 pub struct GenNftAbi;
 
 impl ContractAbi for GenNftAbi {
@@ -207,10 +347,30 @@ impl ServiceAbi for GenNftAbi {
 /// An operation.
 #[derive(Debug, Deserialize, Serialize, GraphQLMutationRoot)]
 pub enum Operation {
+    /// Creates a new independently-governed collection, scoping future `Mint` operations to
+    /// its own namespace, minting authority, and supply cap.
+    CreateCollection {
+        name: String,
+        symbol: String,
+        max_supply: Option<u64>,
+        mint_policy: MintPolicy,
+    },
     /// Mints a token
     Mint {
         minter: AccountOwner,
-        prompt: String,
+        /// The collection this token belongs to. Minting enforces the collection's
+        /// `mint_policy` and `max_supply`.
+        collection_id: CollectionId,
+        /// The recipe that deterministically produced the token's content, including the
+        /// seed chosen when the operation was proposed.
+        recipe: GenerationRecipe,
+        /// Standardized on-chain metadata for the minted token. When omitted, the contract
+        /// fills in both `name` and `description` with the recipe's `prompt`; no generated
+        /// content is stored on-chain.
+        metadata: Option<Metadata>,
+        /// A cut of future sale proceeds reserved for the creator, paid out by
+        /// `TransferWithPayment`.
+        royalty: Option<Royalty>,
     },
     /// Transfers a token from a (locally owned) account to a (possibly remote) account.
     Transfer {
@@ -218,6 +378,16 @@ pub enum Operation {
         token_id: TokenId,
         target_account: Account,
     },
+    /// Transfers a token in exchange for a fungible-token payment, routing the token's
+    /// `royalty` share of `payment_amount` to its recipient via a cross-application call
+    /// before completing the transfer. The transfer is rejected if the payment call fails.
+    TransferWithPayment {
+        source_owner: AccountOwner,
+        token_id: TokenId,
+        target_account: Account,
+        payment_application_id: ApplicationId,
+        payment_amount: Amount,
+    },
     /// Same as `Transfer` but the source account may be remote. Depending on its
     /// configuration, the target chain may take time or refuse to process
     /// the message.
@@ -226,6 +396,26 @@ pub enum Operation {
         token_id: TokenId,
         target_account: Account,
     },
+    /// Destroys a token, removing it from the collection for good. Same as `Claim`, the
+    /// account may be remote: when `source_account` is on another chain, this sends a
+    /// `Message::Burn` there ("a claimed burn") instead of burning locally.
+    Burn {
+        source_account: Account,
+        token_id: TokenId,
+    },
+    /// Approves `approved` to transfer or claim a single token on the owner's behalf, or
+    /// revokes any existing approval when `approved` is `None`. The approval is cleared
+    /// whenever the token is transferred.
+    Approve {
+        token_id: TokenId,
+        approved: Option<AccountOwner>,
+    },
+    /// Approves or revokes `operator` as an operator over all of the signer's tokens, mirroring
+    /// ERC-721's operator-wide delegation.
+    SetApprovalForAll {
+        operator: AccountOwner,
+        approved: bool,
+    },
 }
 
 /// A message.
@@ -241,24 +431,52 @@ pub enum Message {
         token_id: TokenId,
         target_account: Account,
     },
+
+    /// Claims from the given account and burns the token there, unless the message is
+    /// bouncing, in which case the token is left untouched.
+    Burn {
+        source_account: Account,
+        token_id: TokenId,
+    },
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Nft {
     pub token_id: TokenId,
     pub owner: AccountOwner,
-    pub prompt: String,
     pub minter: AccountOwner,
+    pub collection_id: CollectionId,
+    /// The value of the application's minted-NFT counter at the time this token was minted,
+    /// folded into `token_id`'s hash so that two tokens with the same recipe never collide.
+    pub mint_sequence: u64,
+    pub recipe: GenerationRecipe,
+    pub metadata: Metadata,
+    /// The single spender currently approved to transfer or claim this token on the owner's
+    /// behalf, if any. Cleared whenever the token is transferred.
+    pub approved: Option<AccountOwner>,
+    /// A cut of future sale proceeds reserved for the creator, set at mint time and enforced
+    /// by `TransferWithPayment`.
+    pub royalty: Option<Royalty>,
+    /// Where this token came from, if it was ever claimed or transferred onto this chain from
+    /// another one. Populated automatically on the receiving side; `None` for a token that has
+    /// never left its minting chain.
+    pub origin: Option<Provenance>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct NftOutput {
     pub token_id: String,
     pub owner: AccountOwner,
-    pub prompt: String,
     pub minter: AccountOwner,
+    pub collection_id: CollectionId,
+    pub mint_sequence: u64,
+    pub recipe: GenerationRecipe,
+    pub metadata: Metadata,
+    pub approved: Option<AccountOwner>,
+    pub royalty: Option<Royalty>,
+    pub origin: Option<Provenance>,
 }
 
 impl NftOutput {
@@ -268,8 +486,14 @@ impl NftOutput {
         Self {
             token_id,
             owner: nft.owner,
-            prompt: nft.prompt,
             minter: nft.minter,
+            collection_id: nft.collection_id,
+            mint_sequence: nft.mint_sequence,
+            recipe: nft.recipe,
+            metadata: nft.metadata,
+            approved: nft.approved,
+            royalty: nft.royalty,
+            origin: nft.origin,
         }
     }
 
@@ -277,8 +501,232 @@ impl NftOutput {
         Self {
             token_id,
             owner: nft.owner,
-            prompt: nft.prompt,
             minter: nft.minter,
+            collection_id: nft.collection_id,
+            mint_sequence: nft.mint_sequence,
+            recipe: nft.recipe,
+            metadata: nft.metadata,
+            approved: nft.approved,
+            royalty: nft.royalty,
+            origin: nft.origin,
+        }
+    }
+}
+
+/// A cut of future sale proceeds reserved for an NFT's creator, expressed in basis points
+/// (1/100th of a percent) of the sale price. `basis_points` must not exceed `10_000`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, SimpleObject, InputObject)]
+#[graphql(input_name = "RoyaltyInput")]
+#[serde(rename_all = "camelCase")]
+pub struct Royalty {
+    pub recipient: AccountOwner,
+    pub basis_points: u16,
+}
+
+impl Royalty {
+    /// Returns whether `basis_points` is a valid share of a sale price, i.e. at most 100%.
+    pub fn is_valid(&self) -> bool {
+        self.basis_points <= 10_000
+    }
+
+    /// Returns this royalty's share of `sale_price`.
+    pub fn payout(&self, sale_price: Amount) -> Amount {
+        Amount::from(u128::from(sale_price) * u128::from(self.basis_points) / 10_000)
+    }
+}
+
+/// A standardized on-chain metadata record for a minted token, modeled after the
+/// ERC-721/Metaplex/gNFT-721 conventions so that generative NFTs stay interoperable with
+/// existing NFT indexers and marketplaces.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, SimpleObject, InputObject)]
+#[graphql(input_name = "MetadataInput")]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub name: String,
+    pub description: String,
+    pub image_uri: Option<String>,
+    pub external_url: Option<String>,
+    pub attributes: Vec<Attribute>,
+}
+
+impl Metadata {
+    /// Synthesizes metadata from the user-provided prompt and the content generated by the
+    /// LLM, for use when the minter doesn't supply an explicit [`Metadata`] record.
+    pub fn synthesized(prompt: &str, generated_content: &str) -> Self {
+        Self {
+            name: prompt.to_string(),
+            description: generated_content.to_string(),
+            image_uri: None,
+            external_url: None,
+            attributes: Vec::new(),
+        }
+    }
+}
+
+/// A single metadata attribute, following the ERC-721/Metaplex trait-list convention, so that
+/// marketplaces can filter and rank NFTs by trait.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, SimpleObject, InputObject)]
+#[graphql(input_name = "AttributeInput")]
+#[serde(rename_all = "camelCase")]
+pub struct Attribute {
+    pub trait_type: String,
+    pub value: AttributeValue,
+}
+
+/// The value of an [`Attribute`], typed so that marketplaces can filter and rank on it
+/// numerically as well as textually.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum AttributeValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+}
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for AttributeValue {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        match value {
+            async_graphql::Value::String(value) => Ok(AttributeValue::String(value)),
+            async_graphql::Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    Ok(AttributeValue::Int(value))
+                } else if let Some(value) = number.as_f64() {
+                    Ok(AttributeValue::Float(value))
+                } else {
+                    Err(async_graphql::InputValueError::custom(
+                        "expected an integer or a float",
+                    ))
+                }
+            }
+            _ => Err(async_graphql::InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        match self {
+            AttributeValue::String(value) => async_graphql::Value::String(value.clone()),
+            AttributeValue::Int(value) => async_graphql::Value::Number((*value).into()),
+            AttributeValue::Float(value) => async_graphql::Number::from_f64(*value)
+                .map(async_graphql::Value::Number)
+                .unwrap_or(async_graphql::Value::Null),
+        }
+    }
+}
+
+/// The parameters passed to the off-chain generator that produced a minted token's content.
+/// Recorded for provenance and for display, but the chain has no way to re-run the generator
+/// itself, so this is not sufficient on its own to prove the stored content matches the recipe.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SimpleObject, InputObject)]
+#[graphql(input_name = "GenerationRecipeInput")]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationRecipe {
+    pub model_id: String,
+    pub prompt: String,
+    pub seed: u64,
+    pub temperature_milli: u32,
+    pub max_tokens: u32,
+}
+
+/// A collection scopes minting authority and supply to a single, independently-governed
+/// namespace of tokens, so that several generative collections can share one deployed
+/// application without stepping on each other's token IDs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, SimpleObject)]
+#[serde(rename_all = "camelCase")]
+pub struct Collection {
+    pub collection_id: CollectionId,
+    pub name: String,
+    pub symbol: String,
+    pub creator: AccountOwner,
+    pub max_supply: Option<u64>,
+    pub mint_policy: MintPolicy,
+    pub minted_count: u64,
+}
+
+impl Collection {
+    pub fn create_collection_id(
+        chain_id: &ChainId,
+        application_id: &ApplicationId,
+        name: &str,
+        symbol: &str,
+        creator: &AccountOwner,
+        num_created_collections: u64,
+    ) -> Result<CollectionId, bcs::Error> {
+        use sha3::Digest as _;
+
+        let mut hasher = sha3::Sha3_256::new();
+        hasher.update(chain_id.to_bcs_bytes()?);
+        hasher.update(application_id.to_bcs_bytes()?);
+        hasher.update(name);
+        hasher.update(symbol);
+        hasher.update(creator.to_bcs_bytes()?);
+        hasher.update(num_created_collections.to_bcs_bytes()?);
+
+        Ok(CollectionId {
+            id: hasher.finalize().to_vec(),
+        })
+    }
+
+    /// Returns whether `minter` is allowed to mint into this collection under its
+    /// `mint_policy`.
+    pub fn can_mint(&self, minter: &AccountOwner) -> bool {
+        match &self.mint_policy {
+            MintPolicy::Open => true,
+            MintPolicy::CreatorOnly => minter == &self.creator,
+            MintPolicy::Allowlist(allowed) => allowed.contains(minter),
+        }
+    }
+
+    /// Returns whether one more token can be minted without exceeding `max_supply`.
+    pub fn has_remaining_supply(&self) -> bool {
+        self.max_supply
+            .map_or(true, |max_supply| self.minted_count < max_supply)
+    }
+}
+
+/// The minting authority for a [`Collection`]: anyone, only the creator, or a fixed allowlist
+/// of accounts.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum MintPolicy {
+    Open,
+    CreatorOnly,
+    Allowlist(Vec<AccountOwner>),
+}
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for MintPolicy {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        match value {
+            async_graphql::Value::String(value) if value == "open" => Ok(MintPolicy::Open),
+            async_graphql::Value::String(value) if value == "creatorOnly" => {
+                Ok(MintPolicy::CreatorOnly)
+            }
+            async_graphql::Value::List(accounts) => {
+                let accounts = accounts
+                    .into_iter()
+                    .map(|account| match account {
+                        async_graphql::Value::String(account) => account
+                            .parse()
+                            .map_err(async_graphql::InputValueError::custom),
+                        _ => Err(async_graphql::InputValueError::expected_type(account)),
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(MintPolicy::Allowlist(accounts))
+            }
+            _ => Err(async_graphql::InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        match self {
+            MintPolicy::Open => async_graphql::Value::String("open".to_string()),
+            MintPolicy::CreatorOnly => async_graphql::Value::String("creatorOnly".to_string()),
+            MintPolicy::Allowlist(accounts) => async_graphql::Value::List(
+                accounts
+                    .iter()
+                    .map(|account| async_graphql::Value::String(account.to_string()))
+                    .collect(),
+            ),
         }
     }
 }
@@ -289,22 +737,76 @@ impl Display for TokenId {
     }
 }
 
+impl Display for CollectionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.id)
+    }
+}
+
+/// A verifiable trail back to a token's mint site, carried along when it is claimed or
+/// transferred onto a chain other than the one it was minted on.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, SimpleObject)]
+#[serde(rename_all = "camelCase")]
+pub struct Provenance {
+    pub origin_chain: ChainId,
+    pub origin_app: ApplicationId,
+    pub original_token_id: TokenId,
+}
+
 impl Nft {
     pub fn create_token_id(
         chain_id: &ChainId,
         application_id: &ApplicationId,
-        prompt: &String,
+        collection_id: &CollectionId,
+        recipe: &GenerationRecipe,
         minter: &AccountOwner,
-        num_minted_nfts: u64,
+        mint_sequence: u64,
     ) -> Result<TokenId, bcs::Error> {
         use sha3::Digest as _;
 
         let mut hasher = sha3::Sha3_256::new();
         hasher.update(chain_id.to_bcs_bytes()?);
         hasher.update(application_id.to_bcs_bytes()?);
-        hasher.update(prompt);
+        hasher.update(collection_id.to_bcs_bytes()?);
+        hasher.update(recipe.to_bcs_bytes()?);
         hasher.update(minter.to_bcs_bytes()?);
-        hasher.update(num_minted_nfts.to_bcs_bytes()?);
+        hasher.update(mint_sequence.to_bcs_bytes()?);
+
+        Ok(TokenId {
+            id: hasher.finalize().to_vec(),
+        })
+    }
+
+    /// Returns whether `spender` may transfer or claim this token: the owner, the single
+    /// approved spender, or an approved operator for the owner all qualify. `is_operator`
+    /// should reflect the result of looking `spender` up in the owner's operator set.
+    pub fn is_authorized_spender(&self, spender: &AccountOwner, is_operator: bool) -> bool {
+        spender == &self.owner || self.approved.as_ref() == Some(spender) || is_operator
+    }
+
+    /// Derives the `TokenId` a token should carry on `chain_id`/`application_id` given its
+    /// `provenance`. Landing back on the chain and application it was originally minted on
+    /// recovers `provenance.original_token_id` exactly; landing anywhere else derives a
+    /// deterministic wrapped ID from `chain_id`, `application_id` and the provenance tuple,
+    /// so the same origin token
+    /// always maps to the same wrapped ID on a given chain and can't collide with, or
+    /// impersonate, a token minted locally.
+    pub fn create_wrapped_token_id(
+        chain_id: &ChainId,
+        application_id: &ApplicationId,
+        provenance: &Provenance,
+    ) -> Result<TokenId, bcs::Error> {
+        if *chain_id == provenance.origin_chain && *application_id == provenance.origin_app {
+            return Ok(provenance.original_token_id.clone());
+        }
+
+        use sha3::Digest as _;
+
+        let mut hasher = sha3::Sha3_256::new();
+        hasher.update(b"wrapped-nft");
+        hasher.update(chain_id.to_bcs_bytes()?);
+        hasher.update(application_id.to_bcs_bytes()?);
+        hasher.update(provenance.to_bcs_bytes()?);
 
         Ok(TokenId {
             id: hasher.finalize().to_vec(),
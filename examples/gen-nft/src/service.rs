@@ -0,0 +1,208 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+#[path = "state.rs"]
+mod state;
+
+use std::sync::Arc;
+
+use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
+use gen_nft::{CollectionId, GenNftAbi, Nft, Operation, TokenId};
+use linera_sdk::{
+    base::{AccountOwner, WithServiceAbi},
+    graphql::GraphQLMutationRoot,
+    Service, ServiceRuntime,
+};
+
+use self::state::GenNftState;
+
+pub struct GenNftService {
+    state: Arc<GenNftState>,
+    runtime: Arc<ServiceRuntime<Self>>,
+}
+
+linera_sdk::service!(GenNftService);
+
+impl WithServiceAbi for GenNftService {
+    type Abi = GenNftAbi;
+}
+
+impl Service for GenNftService {
+    type Parameters = ();
+
+    async fn new(runtime: ServiceRuntime<Self>) -> Self {
+        let state = GenNftState::load(runtime.root_view_storage_context())
+            .await
+            .expect("failed to load state");
+        GenNftService {
+            state: Arc::new(state),
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    async fn handle_query(&self, request: Request) -> Response {
+        let schema = Schema::build(
+            QueryRoot {
+                state: self.state.clone(),
+            },
+            Operation::mutation_root(self.runtime.clone()),
+            EmptySubscription,
+        )
+        .finish();
+        schema.execute(request).await
+    }
+}
+
+struct QueryRoot {
+    state: Arc<GenNftState>,
+}
+
+#[Object]
+impl QueryRoot {
+    /// Returns a single token by its ID, if it exists and has not been burned.
+    async fn nft(&self, token_id: TokenId) -> Option<Nft> {
+        self.state
+            .nfts
+            .get(&token_id)
+            .await
+            .expect("view access should not fail")
+    }
+
+    /// Returns every token currently held on this chain.
+    async fn nfts(&self) -> Vec<Nft> {
+        let mut nfts = Vec::new();
+        self.state
+            .nfts
+            .for_each_index_value(|_, nft| {
+                nfts.push(nft.into_owned());
+                Ok(())
+            })
+            .await
+            .expect("view access should not fail");
+        nfts
+    }
+
+    /// Returns every token currently owned by `owner`.
+    async fn owned_nfts(&self, owner: AccountOwner) -> Vec<Nft> {
+        let Some(token_ids) = self
+            .state
+            .owned_token_ids
+            .get(&owner)
+            .await
+            .expect("view access should not fail")
+        else {
+            return Vec::new();
+        };
+
+        let mut nfts = Vec::with_capacity(token_ids.len());
+        for token_id in token_ids {
+            if let Some(nft) = self
+                .state
+                .nfts
+                .get(&token_id)
+                .await
+                .expect("view access should not fail")
+            {
+                nfts.push(nft);
+            }
+        }
+        nfts
+    }
+
+    /// Returns every collection created on this chain.
+    async fn collections(&self) -> Vec<gen_nft::Collection> {
+        let mut collections = Vec::new();
+        self.state
+            .collections
+            .for_each_index_value(|_, collection| {
+                collections.push(collection.into_owned());
+                Ok(())
+            })
+            .await
+            .expect("view access should not fail");
+        collections
+    }
+
+    /// Returns the collection with the given ID, if it exists.
+    async fn collection(&self, id: CollectionId) -> Option<gen_nft::Collection> {
+        self.state
+            .collections
+            .get(&id)
+            .await
+            .expect("view access should not fail")
+    }
+
+    /// Returns every token of the given collection currently held on this chain.
+    async fn nfts_in_collection(&self, id: CollectionId) -> Vec<Nft> {
+        let mut nfts = Vec::new();
+        self.state
+            .nfts
+            .for_each_index_value(|_, nft| {
+                if nft.collection_id == id {
+                    nfts.push(nft.into_owned());
+                }
+                Ok(())
+            })
+            .await
+            .expect("view access should not fail");
+        nfts
+    }
+
+    /// Returns the number of tokens currently held on this chain.
+    async fn total_supply(&self) -> u64 {
+        self.state
+            .nfts
+            .count()
+            .await
+            .expect("view access should not fail") as u64
+    }
+
+    /// Returns the token at `index` in mint order among tokens that still exist, letting
+    /// frontends paginate without fetching `nfts` in full.
+    async fn nft_by_index(&self, index: u64) -> Option<Nft> {
+        let mut nfts = self.nfts().await;
+        nfts.sort_by_key(|nft| nft.mint_sequence);
+        nfts.into_iter().nth(index as usize)
+    }
+
+    /// Returns the token id at `index` among the tokens owned by `owner`, letting frontends
+    /// paginate `ownedNfts` without fetching it in full.
+    async fn token_of_owner_by_index(&self, owner: AccountOwner, index: u64) -> Option<TokenId> {
+        let mut nfts = self.owned_nfts(owner).await;
+        nfts.sort_by_key(|nft| nft.mint_sequence);
+        nfts.into_iter().nth(index as usize).map(|nft| nft.token_id)
+    }
+
+    /// Returns the single account currently approved to spend `token_id`, if any.
+    async fn get_approved(&self, token_id: TokenId) -> Option<AccountOwner> {
+        self.state
+            .nfts
+            .get(&token_id)
+            .await
+            .expect("view access should not fail")
+            .and_then(|nft| nft.approved)
+    }
+
+    /// Returns whether `operator` has been approved by `owner` to spend all of their tokens.
+    async fn is_approved_for_all(&self, owner: AccountOwner, operator: AccountOwner) -> bool {
+        self.state
+            .operators
+            .get(&owner)
+            .await
+            .expect("view access should not fail")
+            .map_or(false, |operators| operators.contains(&operator))
+    }
+
+    /// Returns the trail back to `token_id`'s mint site, if it was ever transferred or
+    /// claimed onto this chain from elsewhere.
+    async fn provenance(&self, token_id: TokenId) -> Option<gen_nft::Provenance> {
+        self.state
+            .nfts
+            .get(&token_id)
+            .await
+            .expect("view access should not fail")
+            .and_then(|nft| nft.origin)
+    }
+}
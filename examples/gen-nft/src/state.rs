@@ -0,0 +1,32 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+use gen_nft::{Collection, CollectionId, Nft, TokenId};
+use linera_sdk::{
+    base::AccountOwner,
+    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
+};
+
+/// The application's persisted state.
+#[derive(RootView)]
+#[view(context = "ViewStorageContext")]
+pub struct GenNftState {
+    /// The tokens currently held on this chain, keyed by `TokenId`. Tokens transferred or
+    /// claimed to another chain, and burned tokens, are removed.
+    pub nfts: MapView<TokenId, Nft>,
+    /// All collections that have been created, keyed by `CollectionId`.
+    pub collections: MapView<CollectionId, Collection>,
+    /// The token IDs currently owned by each account, so `ownedNfts` and
+    /// `tokenOfOwnerByIndex` don't need to scan every token.
+    pub owned_token_ids: MapView<AccountOwner, BTreeSet<TokenId>>,
+    /// The operators each account has approved via `SetApprovalForAll`.
+    pub operators: MapView<AccountOwner, BTreeSet<AccountOwner>>,
+    /// The number of tokens minted so far. Folded into every new `TokenId`'s hash and
+    /// persisted on the minted `Nft` as `mint_sequence` so two tokens from the same recipe
+    /// never collide.
+    pub num_minted_nfts: RegisterView<u64>,
+    /// The number of collections created so far. Folded into every new `CollectionId`'s hash.
+    pub num_created_collections: RegisterView<u64>,
+}